@@ -2,7 +2,12 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
-use crate::{errors::CoinFromStrError, math::Uint128};
+use crate::{
+    errors::{CoinFromStrError, DisplayCoinError},
+    ibc::DenomTrace,
+    math::Uint128,
+    metadata::DenomMetadata,
+};
 
 #[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq, JsonSchema)]
 pub struct Coin {
@@ -17,6 +22,106 @@ impl Coin {
             denom: denom.into(),
         }
     }
+
+    /// Returns true if this coin's denom is the `ibc/<HASH>` voucher denom
+    /// for the given [`DenomTrace`].
+    pub fn has_denom_trace(&self, trace: &DenomTrace) -> bool {
+        self.denom == trace.ibc_denom()
+    }
+
+    /// Renders this coin's base-unit amount in `metadata.display` (e.g.
+    /// `1500000uatom` displays as `"1.5 atom"` given a `uatom -> 0, atom ->
+    /// 6` metadata with `display: "atom"`), using the power-of-ten
+    /// exponents in `metadata` to scale it and trimming trailing zeroes.
+    /// `self.denom` must match `metadata.base`.
+    ///
+    /// Use [`Coin::display_as`] to render in a unit other than
+    /// `metadata.display`.
+    pub fn display_with(&self, metadata: &DenomMetadata) -> Result<String, DisplayCoinError> {
+        self.display_as(metadata, &metadata.display)
+    }
+
+    /// Like [`Coin::display_with`], but renders in the given
+    /// `display_denom` instead of `metadata.display`.
+    pub fn display_as(
+        &self,
+        metadata: &DenomMetadata,
+        display_denom: &str,
+    ) -> Result<String, DisplayCoinError> {
+        if self.denom != metadata.base {
+            return Err(DisplayCoinError::DenomMismatch {
+                coin_denom: self.denom.clone(),
+                base_denom: metadata.base.clone(),
+            });
+        }
+
+        let base_exponent = metadata.base_exponent()?;
+        let display_exponent = metadata
+            .unit_exponent(display_denom)
+            .ok_or_else(|| DisplayCoinError::UnknownUnit(display_denom.to_string()))?;
+
+        if display_exponent < base_exponent {
+            // Would require scaling up past the base unit, which none of
+            // the callers of this API need; reject instead of guessing.
+            return Err(DisplayCoinError::UnknownUnit(display_denom.to_string()));
+        }
+
+        let shift = (display_exponent - base_exponent) as usize;
+        let digits = self.amount.to_string();
+
+        let formatted = if shift == 0 {
+            digits
+        } else {
+            let (whole, frac) = if digits.len() > shift {
+                digits.split_at(digits.len() - shift)
+            } else {
+                ("0", digits.as_str())
+            };
+            let frac = format!("{frac:0>shift$}", shift = shift);
+            let frac = frac.trim_end_matches('0');
+            if frac.is_empty() {
+                whole.to_string()
+            } else {
+                format!("{whole}.{frac}")
+            }
+        };
+
+        Ok(format!("{formatted} {display_denom}"))
+    }
+
+    /// The inverse of [`Coin::display_with`]/[`Coin::display_as`]: parses
+    /// `"<amount> <denom>"` (e.g. `"1.5 atom"`) into a base-unit [`Coin`]
+    /// using `metadata`.
+    pub fn parse_with(input: &str, metadata: &DenomMetadata) -> Result<Self, DisplayCoinError> {
+        let (amount, display_denom) = input
+            .trim()
+            .split_once(' ')
+            .ok_or(DisplayCoinError::InvalidFormat)?;
+
+        let base_exponent = metadata.base_exponent()?;
+        let display_exponent = metadata
+            .unit_exponent(display_denom)
+            .ok_or_else(|| DisplayCoinError::UnknownUnit(display_denom.to_string()))?;
+        if display_exponent < base_exponent {
+            return Err(DisplayCoinError::UnknownUnit(display_denom.to_string()));
+        }
+        let shift = (display_exponent - base_exponent) as usize;
+
+        let (whole, frac) = match amount.split_once('.') {
+            Some((w, f)) => (w, f),
+            None => (amount, ""),
+        };
+        if frac.len() > shift {
+            return Err(DisplayCoinError::InvalidFormat);
+        }
+
+        let combined = format!("{whole}{frac:0<shift$}", shift = shift);
+        let base_amount: u128 = combined
+            .parse()
+            .map_err(|_| DisplayCoinError::InvalidFormat)?;
+
+        Ok(Coin::new(base_amount, metadata.base.clone()))
+    }
 }
 
 impl FromStr for Coin {
@@ -106,6 +211,7 @@ pub fn has_coins(coins: &[Coin], required: &Coin) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::metadata::DenomUnit;
 
     #[test]
     fn coin_implements_display() {
@@ -235,4 +341,74 @@ mod tests {
             "Invalid amount: number too large to fit in target type"
         );
     }
+
+    fn uatom_metadata() -> DenomMetadata {
+        DenomMetadata {
+            base: "uatom".to_string(),
+            display: "atom".to_string(),
+            denom_units: vec![DenomUnit::new("uatom", 0), DenomUnit::new("atom", 6)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn display_with_uses_metadata_display_unit() {
+        let metadata = uatom_metadata();
+        let coin = Coin::new(1_500_000, "uatom");
+        assert_eq!(coin.display_with(&metadata).unwrap(), "1.5 atom");
+
+        let whole = Coin::new(2_000_000, "uatom");
+        assert_eq!(whole.display_with(&metadata).unwrap(), "2 atom");
+
+        let small = Coin::new(5, "uatom");
+        assert_eq!(small.display_with(&metadata).unwrap(), "0.000005 atom");
+    }
+
+    #[test]
+    fn display_as_renders_in_an_explicit_unit() {
+        let metadata = uatom_metadata();
+        let coin = Coin::new(1_500_000, "uatom");
+        assert_eq!(coin.display_as(&metadata, "uatom").unwrap(), "1500000 uatom");
+    }
+
+    #[test]
+    fn display_with_rejects_denom_mismatch_and_unknown_unit() {
+        let metadata = uatom_metadata();
+        let coin = Coin::new(1, "uosmo");
+        assert!(matches!(
+            coin.display_with(&metadata).unwrap_err(),
+            DisplayCoinError::DenomMismatch { .. }
+        ));
+
+        let coin = Coin::new(1, "uatom");
+        assert!(matches!(
+            coin.display_as(&metadata, "xatom").unwrap_err(),
+            DisplayCoinError::UnknownUnit(_)
+        ));
+    }
+
+    #[test]
+    fn base_exponent_errors_when_base_has_no_matching_unit() {
+        let metadata = DenomMetadata {
+            base: "uatom".to_string(),
+            display: "atom".to_string(),
+            denom_units: vec![DenomUnit::new("atom", 6)],
+            ..Default::default()
+        };
+        let coin = Coin::new(1, "uatom");
+        assert!(matches!(
+            coin.display_with(&metadata).unwrap_err(),
+            DisplayCoinError::UnknownUnit(u) if u == "uatom"
+        ));
+    }
+
+    #[test]
+    fn parse_with_is_the_inverse_of_display_with() {
+        let metadata = uatom_metadata();
+        let coin = Coin::new(1_500_000, "uatom");
+        let rendered = coin.display_with(&metadata).unwrap();
+        let amount_only = rendered.strip_suffix(" atom").unwrap();
+        let parsed = Coin::parse_with(&format!("{amount_only} atom"), &metadata).unwrap();
+        assert_eq!(parsed, coin);
+    }
 }
@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{AddAssign, SubAssign};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{CheckedMultiplyRatioError, ConversionOverflowError, OverflowError, OverflowOperation},
+    Coin, Uint128,
+};
+
+/// A collection of coins, similar to Cosmos SDK's `sdk.Coins` struct.
+///
+/// Differently from `Vec<Coin>`, `Coins` ensures that there is only one
+/// entry per denom and that all coins have a non-zero amount, and keeps
+/// the individual coins sorted by denom.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(try_from = "Vec<Coin>", into = "Vec<Coin>")]
+pub struct Coins(BTreeMap<String, Coin>);
+
+/// The error type returned from [`Coins`] constructors and arithmetic.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum CoinsError {
+    #[error("Duplicate denom {0}")]
+    DuplicateDenom(String),
+
+    #[error("Missing denom {0}")]
+    MissingDenom(String),
+
+    #[error("Denominator must not be zero")]
+    DivideByZero,
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+}
+
+impl fmt::Display for Coins {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = self
+            .0
+            .values()
+            .map(Coin::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{s}")
+    }
+}
+
+impl TryFrom<Vec<Coin>> for Coins {
+    type Error = CoinsError;
+
+    fn try_from(coins: Vec<Coin>) -> Result<Self, Self::Error> {
+        let mut map = BTreeMap::new();
+        for coin in coins {
+            if coin.amount.is_zero() {
+                continue;
+            }
+            if map.insert(coin.denom.clone(), coin.clone()).is_some() {
+                return Err(CoinsError::DuplicateDenom(coin.denom));
+            }
+        }
+        Ok(Coins(map))
+    }
+}
+
+impl From<Coins> for Vec<Coin> {
+    fn from(coins: Coins) -> Self {
+        coins.into_vec()
+    }
+}
+
+/// The error type returned from [`Coins::from_str`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseCoinsError {
+    #[error("{0}")]
+    Coin(#[from] crate::CoinFromStrError),
+
+    #[error("{0}")]
+    Coins(#[from] CoinsError),
+}
+
+impl FromStr for Coins {
+    type Err = ParseCoinsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Coins::default());
+        }
+
+        let coins = s
+            .split(',')
+            .map(Coin::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Duplicate denoms are merged rather than rejected, matching the
+        // Cosmos SDK's `ParseCoins` behavior.
+        let mut out = Coins::default();
+        for coin in coins {
+            out.add(coin)?;
+        }
+        Ok(out)
+    }
+}
+
+impl IntoIterator for Coins {
+    type Item = Coin;
+    type IntoIter = std::collections::btree_map::IntoValues<String, Coin>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_values()
+    }
+}
+
+impl<'a> IntoIterator for &'a Coins {
+    type Item = &'a Coin;
+    type IntoIter = std::collections::btree_map::Values<'a, String, Coin>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.values()
+    }
+}
+
+impl Coins {
+    /// Returns the number of distinct denoms held.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the amount of the given denom, or zero if it is not held.
+    pub fn amount_of(&self, denom: &str) -> Uint128 {
+        self.0.get(denom).map(|c| c.amount).unwrap_or_default()
+    }
+
+    /// An iterator over the coins in denom order.
+    pub fn iter(&self) -> impl Iterator<Item = &Coin> {
+        self.0.values()
+    }
+
+    /// Converts this into a `Vec<Coin>`, sorted by denom.
+    pub fn into_vec(self) -> Vec<Coin> {
+        self.0.into_values().collect()
+    }
+
+    /// Adds a single coin, merging into an existing denom if present.
+    /// A zero-amount coin is a no-op.
+    pub fn checked_add(&mut self, coin: Coin) -> Result<(), CoinsError> {
+        if coin.amount.is_zero() {
+            return Ok(());
+        }
+        match self.0.get_mut(&coin.denom) {
+            Some(existing) => {
+                existing.amount = existing.amount.checked_add(coin.amount).map_err(|_| {
+                    OverflowError::new(OverflowOperation::Add)
+                })?;
+            }
+            None => {
+                self.0.insert(coin.denom.clone(), coin);
+            }
+        }
+        Ok(())
+    }
+
+    /// Alias for [`Coins::checked_add`].
+    pub fn add(&mut self, coin: Coin) -> Result<(), CoinsError> {
+        self.checked_add(coin)
+    }
+
+    /// Subtracts a single coin. Errors if the denom is missing or the
+    /// subtraction would underflow. A denom whose balance reaches zero is
+    /// removed entirely.
+    pub fn checked_sub(&mut self, coin: Coin) -> Result<(), CoinsError> {
+        let existing = self
+            .0
+            .get_mut(&coin.denom)
+            .ok_or_else(|| CoinsError::MissingDenom(coin.denom.clone()))?;
+
+        let remainder = existing
+            .amount
+            .checked_sub(coin.amount)
+            .map_err(|_| OverflowError::new(OverflowOperation::Sub))?;
+
+        if remainder.is_zero() {
+            self.0.remove(&coin.denom);
+        } else {
+            existing.amount = remainder;
+        }
+        Ok(())
+    }
+
+    /// Alias for [`Coins::checked_sub`].
+    pub fn sub(&mut self, coin: Coin) -> Result<(), CoinsError> {
+        self.checked_sub(coin)
+    }
+
+    /// Scales every coin in this set by `numerator / denominator`, rounding
+    /// down, using a `Uint256` intermediate to avoid overflow.
+    pub fn checked_mul_ratio(
+        &self,
+        numerator: impl Into<u128>,
+        denominator: impl Into<u128>,
+    ) -> Result<Self, CoinsError> {
+        let numerator = numerator.into();
+        let denominator = denominator.into();
+
+        let mut out = Coins::default();
+        for coin in self.iter() {
+            let amount = coin
+                .amount
+                .checked_multiply_ratio(numerator, denominator)
+                .map_err(|err| match err {
+                    CheckedMultiplyRatioError::DivideByZero => CoinsError::DivideByZero,
+                    CheckedMultiplyRatioError::Overflow => {
+                        CoinsError::Overflow(OverflowError::new(OverflowOperation::Mul))
+                    }
+                })?;
+            out.checked_add(Coin::new(amount.u128(), coin.denom.clone()))?;
+        }
+        Ok(out)
+    }
+
+    /// Alias for [`Coins::checked_mul_ratio`].
+    pub fn checked_multiply_ratio(
+        &self,
+        numerator: impl Into<u128>,
+        denominator: impl Into<u128>,
+    ) -> Result<Self, CoinsError> {
+        self.checked_mul_ratio(numerator, denominator)
+    }
+}
+
+impl AddAssign<Coin> for Coins {
+    fn add_assign(&mut self, rhs: Coin) {
+        self.checked_add(rhs).unwrap();
+    }
+}
+
+impl SubAssign<Coin> for Coins {
+    fn sub_assign(&mut self, rhs: Coin) {
+        self.checked_sub(rhs).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coins(amount: u128, denom: &str) -> Coin {
+        Coin::new(amount, denom)
+    }
+
+    #[test]
+    fn add_merges_existing_denom() {
+        let mut c = Coins::default();
+        c.add(coins(100, "uatom")).unwrap();
+        c.add(coins(50, "uatom")).unwrap();
+        assert_eq!(c.amount_of("uatom"), Uint128::new(150));
+        assert_eq!(c.len(), 1);
+    }
+
+    #[test]
+    fn add_inserts_new_denom() {
+        let mut c = Coins::default();
+        c.add(coins(100, "uatom")).unwrap();
+        c.add(coins(50, "uosmo")).unwrap();
+        assert_eq!(c.len(), 2);
+    }
+
+    #[test]
+    fn sub_removes_denom_at_zero() {
+        let mut c = Coins::default();
+        c.add(coins(100, "uatom")).unwrap();
+        c.sub(coins(100, "uatom")).unwrap();
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn sub_errors_on_missing_denom() {
+        let mut c = Coins::default();
+        assert_eq!(
+            c.sub(coins(1, "uatom")).unwrap_err(),
+            CoinsError::MissingDenom("uatom".to_string())
+        );
+    }
+
+    #[test]
+    fn sub_errors_on_underflow() {
+        let mut c = Coins::default();
+        c.add(coins(50, "uatom")).unwrap();
+        assert!(c.sub(coins(100, "uatom")).is_err());
+    }
+
+    #[test]
+    fn add_assign_and_sub_assign_operators() {
+        let mut c = Coins::default();
+        c += coins(100, "uatom");
+        c += coins(25, "uatom");
+        assert_eq!(c.amount_of("uatom"), Uint128::new(125));
+        c -= coins(25, "uatom");
+        assert_eq!(c.amount_of("uatom"), Uint128::new(100));
+    }
+
+    #[test]
+    fn checked_mul_ratio_scales_every_denom() {
+        let mut c = Coins::default();
+        c.add(coins(100, "uatom")).unwrap();
+        c.add(coins(200, "uosmo")).unwrap();
+        let scaled = c.checked_mul_ratio(1u128, 2u128).unwrap();
+        assert_eq!(scaled.amount_of("uatom"), Uint128::new(50));
+        assert_eq!(scaled.amount_of("uosmo"), Uint128::new(100));
+    }
+
+    #[test]
+    fn checked_mul_ratio_errors_on_zero_denominator() {
+        let mut c = Coins::default();
+        c.add(coins(100, "uatom")).unwrap();
+        assert_eq!(
+            c.checked_mul_ratio(1u128, 0u128).unwrap_err(),
+            CoinsError::DivideByZero
+        );
+    }
+
+    #[test]
+    fn from_str_parses_and_merges_duplicate_denoms() {
+        let c: Coins = "100uatom,50uosmo,25uatom".parse().unwrap();
+        assert_eq!(c.amount_of("uatom"), Uint128::new(125));
+        assert_eq!(c.amount_of("uosmo"), Uint128::new(50));
+    }
+
+    #[test]
+    fn from_str_reports_overflow_as_such_not_as_missing_amount() {
+        let input = format!("{}uatom,1uatom", u128::MAX);
+        let err = input.parse::<Coins>().unwrap_err();
+        assert!(matches!(err, ParseCoinsError::Coins(CoinsError::Overflow(_))));
+    }
+}
@@ -0,0 +1,217 @@
+use std::num::ParseIntError;
+
+use thiserror::Error;
+
+/// The return type for init, execute and query. Since errors are created in
+/// many places, have a look at `crate::StdError` for more information.
+pub type StdResult<T> = Result<T, StdError>;
+
+/// Structured error type for init, execute and query.
+///
+/// This can be serialized and passed over the wasm/VM boundary, which allows
+/// us to use structured error types.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum StdError {
+    #[error("Generic error: {msg}")]
+    GenericErr { msg: String },
+
+    #[error("Invalid Base64 string: {msg}")]
+    InvalidBase64 { msg: String },
+
+    #[error("Invalid UTF-8 string: {msg}")]
+    InvalidUtf8 { msg: String },
+
+    #[error("{kind} not found")]
+    NotFound { kind: String },
+
+    #[error("Error parsing into type {target_type}: {msg}")]
+    ParseErr { target_type: String, msg: String },
+
+    #[error("Error serializing into type {target_type}: {msg}")]
+    SerializeErr { target_type: String, msg: String },
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    DivideByZero(#[from] DivideByZeroError),
+
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+}
+
+impl StdError {
+    pub fn generic_err(msg: impl Into<String>) -> Self {
+        StdError::GenericErr { msg: msg.into() }
+    }
+
+    pub fn parse_err(target: impl Into<String>, msg: impl ToString) -> Self {
+        StdError::ParseErr {
+            target_type: target.into(),
+            msg: msg.to_string(),
+        }
+    }
+}
+
+/// The error returned by [`TryFrom`] conversions that overflow, e.g.
+/// converting a `Uint256` into a `Uint128`.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Error converting {source_type} to {target_type}")]
+pub struct ConversionOverflowError {
+    pub source_type: &'static str,
+    pub target_type: &'static str,
+}
+
+impl ConversionOverflowError {
+    pub fn new(source_type: &'static str, target_type: &'static str) -> Self {
+        Self {
+            source_type,
+            target_type,
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OverflowOperation {
+    Add,
+    Sub,
+    Mul,
+    Pow,
+    Shr,
+    Shl,
+    Abs,
+    Neg,
+}
+
+impl std::fmt::Display for OverflowOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Cannot {operation} with given operands")]
+pub struct OverflowError {
+    pub operation: OverflowOperation,
+}
+
+impl OverflowError {
+    pub fn new(operation: OverflowOperation) -> Self {
+        Self { operation }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Division by zero")]
+pub struct DivideByZeroError;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DivisionError {
+    #[error("Divide by zero error")]
+    DivideByZero,
+
+    #[error("Overflow in division")]
+    Overflow,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CheckedMultiplyFractionError {
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    DivideByZero(#[from] DivideByZeroError),
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CheckedMultiplyRatioError {
+    #[error("Denominator must not be zero")]
+    DivideByZero,
+
+    #[error("Multiplication overflow")]
+    Overflow,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CheckedFromRatioError {
+    #[error("Denominator must not be zero")]
+    DivideByZero,
+
+    #[error("Overflow")]
+    Overflow,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum RecoverPubkeyError {
+    #[error("Invalid hash format")]
+    InvalidHashFormat,
+
+    #[error("Invalid signature format")]
+    InvalidSignatureFormat,
+
+    #[error("Invalid recovery parameter")]
+    InvalidRecoveryParam,
+
+    #[error("Unknown error: {error_code}")]
+    UnknownErr { error_code: u32 },
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum VerificationError {
+    #[error("Invalid hash format")]
+    InvalidHashFormat,
+
+    #[error("Invalid signature format")]
+    InvalidSignatureFormat,
+
+    #[error("Invalid public key format")]
+    InvalidPubkeyFormat,
+
+    #[error("Unknown error: {error_code}")]
+    UnknownErr { error_code: u32 },
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SystemError {
+    #[error("Cannot parse request: {error} in: {request}")]
+    InvalidRequest { error: String, request: String },
+
+    #[error("Unsupported query type: {kind}")]
+    UnsupportedRequest { kind: String },
+
+    #[error("No such contract: {address}")]
+    NoSuchContract { address: String },
+}
+
+/// The error type returned from [`crate::Coin::display_with`] and
+/// [`crate::Coin::parse_with`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum DisplayCoinError {
+    #[error("Coin denom {coin_denom} does not match metadata base denom {base_denom}")]
+    DenomMismatch {
+        coin_denom: String,
+        base_denom: String,
+    },
+
+    #[error("Unknown denom unit: {0}")]
+    UnknownUnit(String),
+
+    #[error("Invalid display amount format")]
+    InvalidFormat,
+}
+
+/// The error type returned from `Coin::from_str`.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CoinFromStrError {
+    #[error("Missing denominator")]
+    MissingDenom,
+
+    #[error("Missing amount or non-digit characters in amount")]
+    MissingAmount,
+
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(#[from] ParseIntError),
+}
@@ -0,0 +1,449 @@
+use std::fmt;
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{Addr, Attribute, Binary, Coin, CosmosMsg, Empty, Event, StdResult, Timestamp};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcEndpoint {
+    pub port_id: String,
+    pub channel_id: String,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcOrder {
+    Unordered,
+    Ordered,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcTimeoutBlock {
+    pub revision: u64,
+    pub height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcTimeout {
+    block: Option<IbcTimeoutBlock>,
+    timestamp: Option<Timestamp>,
+}
+
+impl IbcTimeout {
+    pub fn with_block(block: IbcTimeoutBlock) -> Self {
+        IbcTimeout {
+            block: Some(block),
+            timestamp: None,
+        }
+    }
+
+    pub fn with_timestamp(timestamp: Timestamp) -> Self {
+        IbcTimeout {
+            block: None,
+            timestamp: Some(timestamp),
+        }
+    }
+
+    pub fn block(&self) -> Option<IbcTimeoutBlock> {
+        self.block
+    }
+
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.timestamp
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcChannel {
+    pub endpoint: IbcEndpoint,
+    pub counterparty_endpoint: IbcEndpoint,
+    pub order: IbcOrder,
+    pub version: String,
+    pub connection_id: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcChannelOpenMsg {
+    OpenInit { channel: IbcChannel },
+    OpenTry {
+        channel: IbcChannel,
+        counterparty_version: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcChannelConnectMsg {
+    OpenAck {
+        channel: IbcChannel,
+        counterparty_version: String,
+    },
+    OpenConfirm { channel: IbcChannel },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcChannelCloseMsg {
+    CloseInit { channel: IbcChannel },
+    CloseConfirm { channel: IbcChannel },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Default)]
+pub struct Ibc3ChannelOpenResponse {
+    pub version: String,
+}
+
+pub type IbcChannelOpenResponse = Option<Ibc3ChannelOpenResponse>;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcPacket {
+    pub data: Binary,
+    pub src: IbcEndpoint,
+    pub dest: IbcEndpoint,
+    pub sequence: u64,
+    pub timeout: IbcTimeout,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcAcknowledgement {
+    pub data: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcPacketReceiveMsg {
+    pub packet: IbcPacket,
+    pub relayer: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcPacketAckMsg {
+    pub acknowledgement: IbcAcknowledgement,
+    pub original_packet: IbcPacket,
+    pub relayer: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct IbcPacketTimeoutMsg {
+    pub packet: IbcPacket,
+    pub relayer: Addr,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IbcBasicResponse<T = Empty> {
+    pub messages: Vec<CosmosMsg<T>>,
+    pub attributes: Vec<Attribute>,
+    pub events: Vec<Event>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IbcReceiveResponse<T = Empty> {
+    pub acknowledgement: Option<Binary>,
+    pub messages: Vec<CosmosMsg<T>>,
+    pub attributes: Vec<Attribute>,
+    pub events: Vec<Event>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcMsg {
+    Transfer {
+        channel_id: String,
+        to_address: String,
+        amount: Coin,
+        timeout: IbcTimeout,
+        /// An optional memo for middleware such as IBC-hooks or
+        /// packet-forward-middleware. Omitted from the wire format
+        /// entirely when `None`, so existing transfers serialize unchanged.
+        /// See [`IbcForwardMemo`] to build a multi-hop forward.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        memo: Option<String>,
+    },
+    SendPacket {
+        channel_id: String,
+        data: Binary,
+        timeout: IbcTimeout,
+    },
+    CloseChannel {
+        channel_id: String,
+    },
+}
+
+/// A builder for the well-known packet-forward-middleware memo shape, so a
+/// contract can express recursive multi-hop IBC forwards without
+/// hand-assembling JSON. Pass the result of [`IbcForwardMemo::into_memo`]
+/// as [`IbcMsg::Transfer`]'s `memo` field.
+///
+/// See <https://github.com/strangelove-ventures/packet-forward-middleware>.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Default)]
+pub struct IbcForwardMemo {
+    pub receiver: String,
+    pub port: String,
+    pub channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<IbcForwardMemo>>,
+}
+
+impl IbcForwardMemo {
+    pub fn new(
+        receiver: impl Into<String>,
+        port: impl Into<String>,
+        channel: impl Into<String>,
+    ) -> Self {
+        IbcForwardMemo {
+            receiver: receiver.into(),
+            port: port.into(),
+            channel: channel.into(),
+            timeout: None,
+            retries: None,
+            next: None,
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: impl Into<String>) -> Self {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u8) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Nests another forward hop inside this one, enabling multi-hop
+    /// routing.
+    pub fn with_next(mut self, next: IbcForwardMemo) -> Self {
+        self.next = Some(Box::new(next));
+        self
+    }
+
+    /// Renders this forward as the `{"forward": {...}}` memo JSON string
+    /// expected by packet-forward-middleware.
+    pub fn into_memo(self) -> StdResult<String> {
+        crate::to_json_string(&serde_json::json!({ "forward": self }))
+    }
+}
+
+/// The path and base denomination that make up an ICS-20 voucher denom trace,
+/// e.g. `path: "transfer/channel-0"`, `base_denom: "uatom"`.
+///
+/// See <https://github.com/cosmos/ibc-go/blob/main/docs/architecture/adr-001-coin-source-tracing.md>.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenomTrace {
+    /// The chain of `port/channel` pairs the token has traversed, without a
+    /// trailing slash. Empty for a native (non-IBC) denom.
+    pub path: String,
+    /// The original, non-IBC denomination.
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    pub fn new(path: impl Into<String>, base_denom: impl Into<String>) -> Self {
+        DenomTrace {
+            path: path.into(),
+            base_denom: base_denom.into(),
+        }
+    }
+
+    /// The full trace as it is hashed on-chain: `"<path>/<base_denom>"`, or
+    /// just `base_denom` if `path` is empty.
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Computes the on-chain voucher denom: `"ibc/"` followed by the
+    /// uppercase hex-encoded sha256 hash of [`Self::full_path`].
+    pub fn ibc_denom(&self) -> String {
+        let hash = Sha256::digest(self.full_path().as_bytes());
+        format!("ibc/{:X}", HexUpper(&hash))
+    }
+}
+
+/// Formats a byte slice as uppercase hex without pulling in an extra
+/// dependency just for this.
+struct HexUpper<'a>(&'a [u8]);
+
+impl fmt::UpperHex for HexUpper<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The separator between the segments of a denom trace, e.g. between
+/// `"transfer"`, `"channel-0"` and `"uatom"` in `"transfer/channel-0/uatom"`.
+const DENOM_TRACE_SEPARATOR: char = '/';
+
+/// Error returned by [`DenomTrace::from_str`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseDenomTraceError {
+    #[error("Denom trace path is empty")]
+    Empty,
+}
+
+/// Whether `segment` looks like an IBC channel identifier, e.g. `channel-0`
+/// or `channel-52`, per IBC-go's `channel-{sequence}` naming convention.
+fn looks_like_channel_id(segment: &str) -> bool {
+    segment
+        .strip_prefix("channel-")
+        .is_some_and(|rest| !rest.is_empty() && rest.bytes().all(|b| b.is_ascii_digit()))
+}
+
+impl FromStr for DenomTrace {
+    type Err = ParseDenomTraceError;
+
+    /// Peels leading `port/channel-N` pairs off the front of `full_path`
+    /// while the next segment actually looks like a channel identifier,
+    /// leaving everything else (which may itself contain slashes) as
+    /// `base_denom`.
+    fn from_str(full_path: &str) -> Result<Self, Self::Err> {
+        if full_path.is_empty() {
+            return Err(ParseDenomTraceError::Empty);
+        }
+
+        let parts: Vec<&str> = full_path.split(DENOM_TRACE_SEPARATOR).collect();
+        // A `port/channel` pair is only peeled off while there's still at
+        // least one segment left over for the base denom, and the channel
+        // segment actually matches `channel-{sequence}`.
+        let mut prefix_len = 0;
+        while prefix_len + 2 < parts.len() && looks_like_channel_id(parts[prefix_len + 1]) {
+            prefix_len += 2;
+        }
+
+        let path = parts[..prefix_len].join(&DENOM_TRACE_SEPARATOR.to_string());
+        let base_denom = parts[prefix_len..].join(&DENOM_TRACE_SEPARATOR.to_string());
+
+        Ok(DenomTrace { path, base_denom })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ibc_denom_matches_ibc_go_test_vector() {
+        let trace = DenomTrace::new("transfer/channel-0", "uatom");
+        assert_eq!(trace.full_path(), "transfer/channel-0/uatom");
+        assert_eq!(
+            trace.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn parses_native_denom_with_no_trace() {
+        let trace: DenomTrace = "uatom".parse().unwrap();
+        assert_eq!(trace.path, "");
+        assert_eq!(trace.base_denom, "uatom");
+    }
+
+    #[test]
+    fn parses_single_hop_trace() {
+        let trace: DenomTrace = "transfer/channel-0/uatom".parse().unwrap();
+        assert_eq!(trace.path, "transfer/channel-0");
+        assert_eq!(trace.base_denom, "uatom");
+    }
+
+    #[test]
+    fn parses_multi_hop_trace() {
+        let trace: DenomTrace = "transfer/channel-0/transfer/channel-52/uatom".parse().unwrap();
+        assert_eq!(trace.path, "transfer/channel-0/transfer/channel-52");
+        assert_eq!(trace.base_denom, "uatom");
+    }
+
+    #[test]
+    fn keeps_slashes_in_base_denom() {
+        let trace: DenomTrace = "transfer/channel-0/gamm/pool/1".parse().unwrap();
+        assert_eq!(trace.path, "transfer/channel-0");
+        assert_eq!(trace.base_denom, "gamm/pool/1");
+    }
+
+    #[test]
+    fn does_not_peel_a_segment_that_is_not_a_channel_id() {
+        // "pool" does not look like a channel id, so nothing is peeled at
+        // all, even though the segment count would otherwise allow it.
+        let trace: DenomTrace = "gamm/pool/1".parse().unwrap();
+        assert_eq!(trace.path, "");
+        assert_eq!(trace.base_denom, "gamm/pool/1");
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!("".parse::<DenomTrace>().unwrap_err(), ParseDenomTraceError::Empty);
+    }
+
+    fn transfer_msg(memo: Option<String>) -> IbcMsg {
+        IbcMsg::Transfer {
+            channel_id: "channel-0".to_string(),
+            to_address: "cosmos1...".to_string(),
+            amount: Coin::new(100, "uatom"),
+            timeout: IbcTimeout::with_block(IbcTimeoutBlock {
+                revision: 1,
+                height: 12345,
+            }),
+            memo,
+        }
+    }
+
+    #[test]
+    fn transfer_without_memo_serializes_like_before_the_field_existed() {
+        let json = serde_json::to_string(&transfer_msg(None)).unwrap();
+        assert!(!json.contains("memo"));
+
+        let round_tripped: IbcMsg = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, transfer_msg(None));
+    }
+
+    #[test]
+    fn transfer_with_memo_round_trips() {
+        let msg = transfer_msg(Some("hello".to_string()));
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("\"memo\":\"hello\""));
+
+        let round_tripped: IbcMsg = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, msg);
+    }
+
+    #[test]
+    fn forward_memo_builds_expected_json_shape() {
+        let memo = IbcForwardMemo::new("cosmos1receiver", "transfer", "channel-1")
+            .with_timeout("10m")
+            .with_retries(2)
+            .into_memo()
+            .unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&memo).unwrap();
+        assert_eq!(value["forward"]["receiver"], "cosmos1receiver");
+        assert_eq!(value["forward"]["port"], "transfer");
+        assert_eq!(value["forward"]["channel"], "channel-1");
+        assert_eq!(value["forward"]["timeout"], "10m");
+        assert_eq!(value["forward"]["retries"], 2);
+        assert!(value["forward"]["next"].is_null());
+    }
+
+    #[test]
+    fn forward_memo_supports_multi_hop_nesting() {
+        let inner = IbcForwardMemo::new("cosmos1final", "transfer", "channel-2");
+        let memo = IbcForwardMemo::new("cosmos1mid", "transfer", "channel-1")
+            .with_next(inner)
+            .into_memo()
+            .unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&memo).unwrap();
+        assert_eq!(value["forward"]["next"]["receiver"], "cosmos1final");
+    }
+}
@@ -0,0 +1,497 @@
+use std::fmt;
+use std::ops::Neg;
+use std::str::FromStr;
+
+use bnum::types::I256;
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    errors::{ConversionOverflowError, DivideByZeroError, OverflowError, OverflowOperation},
+    Coin, Uint128, Uint256,
+};
+
+macro_rules! impl_int_serde {
+    ($t:ty) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+/// A signed 128-bit integer, for contracts that need negative deltas (e.g.
+/// net credits/debits) alongside the existing unsigned [`Uint128`].
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
+pub struct Int128(i128);
+
+impl Int128 {
+    pub const MAX: Self = Self(i128::MAX);
+    pub const MIN: Self = Self(i128::MIN);
+
+    pub const fn new(value: i128) -> Self {
+        Self(value)
+    }
+
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    pub const fn i128(&self) -> i128 {
+        self.0
+    }
+
+    /// The absolute value. Errors on `Int128::MIN`, whose magnitude doesn't
+    /// fit in an `Int128`.
+    pub fn checked_abs(self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_abs()
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Abs))
+    }
+
+    pub fn signum(self) -> Self {
+        Self(self.0.signum())
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_add(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_sub(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Sub))
+    }
+
+    pub fn checked_mul(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_mul(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Mul))
+    }
+
+    pub fn checked_div(self, other: Self) -> Result<Self, DivideByZeroError> {
+        self.0.checked_div(other.0).map(Self).ok_or(DivideByZeroError)
+    }
+
+    pub fn checked_rem(self, other: Self) -> Result<Self, DivideByZeroError> {
+        self.0.checked_rem(other.0).map(Self).ok_or(DivideByZeroError)
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Self, OverflowError> {
+        self.0
+            .checked_pow(exp)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self(self.0.saturating_mul(other.0))
+    }
+
+    /// The negation. Errors on `Int128::MIN`, whose negation doesn't fit in
+    /// an `Int128`.
+    pub fn checked_neg(self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_neg()
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Neg))
+    }
+}
+
+impl Neg for Int128 {
+    type Output = Self;
+
+    /// Panics on `Int128::MIN`, whose negation doesn't fit in an `Int128`.
+    /// Use [`Int128::checked_neg`] to handle that case without panicking.
+    fn neg(self) -> Self {
+        self.checked_neg().unwrap()
+    }
+}
+
+impl fmt::Display for Int128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Int128 {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<i128>().map(Self)
+    }
+}
+
+impl From<i128> for Int128 {
+    fn from(value: i128) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<Uint128> for Int128 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: Uint128) -> Result<Self, Self::Error> {
+        i128::try_from(value.u128())
+            .map(Self)
+            .map_err(|_| ConversionOverflowError::new("Uint128", "Int128"))
+    }
+}
+
+impl TryFrom<Int128> for Uint128 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: Int128) -> Result<Self, Self::Error> {
+        u128::try_from(value.0)
+            .map(Uint128::new)
+            .map_err(|_| ConversionOverflowError::new("Int128", "Uint128"))
+    }
+}
+
+impl TryFrom<&Coin> for Int128 {
+    type Error = ConversionOverflowError;
+
+    /// Reads `coin.amount` as a non-negative `Int128`. Errors if the amount
+    /// exceeds `Int128::MAX`.
+    fn try_from(coin: &Coin) -> Result<Self, Self::Error> {
+        Int128::try_from(coin.amount)
+    }
+}
+
+impl_int_serde!(Int128);
+
+/// A signed 256-bit integer, backed by [`bnum`], for price/ratio math that
+/// would overflow 128 bits (e.g. `coin_a.amount * price_num / price_den`
+/// through a wider intermediate).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
+pub struct Int256(I256);
+
+impl Default for Int256 {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl Int256 {
+    pub fn zero() -> Self {
+        Self(I256::ZERO)
+    }
+
+    /// The absolute value. Errors on `Int256::MIN`, whose magnitude doesn't
+    /// fit in an `Int256`.
+    pub fn checked_abs(self) -> Result<Self, OverflowError> {
+        if self.0 == I256::MIN {
+            return Err(OverflowError::new(OverflowOperation::Abs));
+        }
+        Ok(if self.0 < I256::ZERO {
+            Self(-self.0)
+        } else {
+            self
+        })
+    }
+
+    pub fn signum(self) -> Self {
+        match self.0.cmp(&I256::ZERO) {
+            std::cmp::Ordering::Less => Self(-I256::ONE),
+            std::cmp::Ordering::Equal => Self::zero(),
+            std::cmp::Ordering::Greater => Self(I256::ONE),
+        }
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_add(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_sub(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Sub))
+    }
+
+    pub fn checked_mul(self, other: Self) -> Result<Self, OverflowError> {
+        self.0
+            .checked_mul(other.0)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Mul))
+    }
+
+    pub fn checked_div(self, other: Self) -> Result<Self, DivideByZeroError> {
+        self.0.checked_div(other.0).map(Self).ok_or(DivideByZeroError)
+    }
+
+    pub fn checked_rem(self, other: Self) -> Result<Self, DivideByZeroError> {
+        self.0.checked_rem(other.0).map(Self).ok_or(DivideByZeroError)
+    }
+
+    pub fn checked_pow(self, exp: u32) -> Result<Self, OverflowError> {
+        self.0
+            .checked_pow(exp)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Pow))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        Self(self.0.saturating_mul(other.0))
+    }
+
+    /// The negation. Errors on `Int256::MIN`, whose negation doesn't fit in
+    /// an `Int256`.
+    pub fn checked_neg(self) -> Result<Self, OverflowError> {
+        if self.0 == I256::MIN {
+            return Err(OverflowError::new(OverflowOperation::Neg));
+        }
+        Ok(Self(-self.0))
+    }
+}
+
+impl Neg for Int256 {
+    type Output = Self;
+
+    /// Panics on `Int256::MIN`, whose negation doesn't fit in an `Int256`.
+    /// Use [`Int256::checked_neg`] to handle that case without panicking.
+    fn neg(self) -> Self {
+        self.checked_neg().unwrap()
+    }
+}
+
+impl fmt::Display for Int256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Error returned by [`Int256::from_str`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Invalid Int256: {0}")]
+pub struct ParseInt256Error(String);
+
+impl FromStr for Int256 {
+    type Err = ParseInt256Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        I256::from_str_radix(s, 10)
+            .map(Self)
+            .map_err(|_| ParseInt256Error(s.to_string()))
+    }
+}
+
+impl From<Int128> for Int256 {
+    fn from(value: Int128) -> Self {
+        Self(I256::from(value.i128()))
+    }
+}
+
+impl TryFrom<Int256> for Int128 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: Int256) -> Result<Self, Self::Error> {
+        i128::try_from(value.0)
+            .map(Int128::new)
+            .map_err(|_| ConversionOverflowError::new("Int256", "Int128"))
+    }
+}
+
+impl TryFrom<Uint256> for Int256 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: Uint256) -> Result<Self, Self::Error> {
+        I256::try_from(value)
+            .map(Self)
+            .map_err(|_| ConversionOverflowError::new("Uint256", "Int256"))
+    }
+}
+
+impl TryFrom<Int256> for Uint256 {
+    type Error = ConversionOverflowError;
+
+    fn try_from(value: Int256) -> Result<Self, Self::Error> {
+        Uint256::try_from(value.0)
+            .map_err(|_| ConversionOverflowError::new("Int256", "Uint256"))
+    }
+}
+
+impl_int_serde!(Int256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int128_checked_arithmetic() {
+        let a = Int128::new(10);
+        let b = Int128::new(3);
+        assert_eq!(a.checked_add(b).unwrap(), Int128::new(13));
+        assert_eq!(a.checked_sub(b).unwrap(), Int128::new(7));
+        assert_eq!(a.checked_mul(b).unwrap(), Int128::new(30));
+        assert_eq!(a.checked_div(b).unwrap(), Int128::new(3));
+        assert_eq!(a.checked_rem(b).unwrap(), Int128::new(1));
+        assert_eq!(Int128::new(-7).checked_abs().unwrap(), Int128::new(7));
+        assert_eq!(Int128::new(-7).signum(), Int128::new(-1));
+    }
+
+    #[test]
+    fn int128_abs_errors_on_min() {
+        assert_eq!(
+            Int128::MIN.checked_abs().unwrap_err(),
+            OverflowError::new(OverflowOperation::Abs)
+        );
+    }
+
+    #[test]
+    fn int128_neg_errors_on_min() {
+        assert_eq!(
+            Int128::MIN.checked_neg().unwrap_err(),
+            OverflowError::new(OverflowOperation::Neg)
+        );
+        assert_eq!(-Int128::new(7), Int128::new(-7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn int128_neg_panics_on_min() {
+        let _ = -Int128::MIN;
+    }
+
+    #[test]
+    fn int128_checked_div_by_zero_errors() {
+        assert_eq!(
+            Int128::new(1).checked_div(Int128::zero()).unwrap_err(),
+            DivideByZeroError
+        );
+    }
+
+    #[test]
+    fn int128_overflow_errors() {
+        assert!(Int128::MAX.checked_add(Int128::new(1)).is_err());
+        assert!(Int128::MIN.checked_sub(Int128::new(1)).is_err());
+    }
+
+    #[test]
+    fn int128_uint128_conversions() {
+        let u = Uint128::new(42);
+        let i = Int128::try_from(u).unwrap();
+        assert_eq!(i, Int128::new(42));
+        assert_eq!(Uint128::try_from(i).unwrap(), u);
+        assert!(Uint128::try_from(Int128::new(-1)).is_err());
+    }
+
+    #[test]
+    fn int128_uint128_conversion_errors_above_i128_max() {
+        let u = Uint128::new(u128::MAX);
+        assert_eq!(
+            Int128::try_from(u).unwrap_err(),
+            ConversionOverflowError::new("Uint128", "Int128")
+        );
+    }
+
+    #[test]
+    fn int128_coin_amount_conversion() {
+        let coin = Coin::new(100, "uatom");
+        let i = Int128::try_from(&coin).unwrap();
+        assert_eq!(i, Int128::new(100));
+    }
+
+    #[test]
+    fn int256_checked_arithmetic_through_wide_intermediate() {
+        let amount = Int256::from(Int128::new(i128::MAX));
+        let doubled = amount.checked_add(amount).unwrap();
+        assert!(Int128::try_from(doubled).is_err());
+        assert_eq!(
+            Int128::try_from(doubled.checked_div(Int256::from(Int128::new(2))).unwrap())
+                .unwrap(),
+            Int128::new(i128::MAX)
+        );
+    }
+
+    #[test]
+    fn int256_abs_and_signum() {
+        assert_eq!(
+            Int256::from(Int128::new(-5)).checked_abs().unwrap(),
+            Int256::from(Int128::new(5))
+        );
+        assert_eq!(
+            Int256::from(Int128::new(-5)).signum(),
+            Int256::from(Int128::new(-1))
+        );
+    }
+
+    #[test]
+    fn int256_abs_errors_on_min() {
+        assert_eq!(
+            Int256(I256::MIN).checked_abs().unwrap_err(),
+            OverflowError::new(OverflowOperation::Abs)
+        );
+    }
+
+    #[test]
+    fn int256_neg_errors_on_min() {
+        assert_eq!(
+            Int256(I256::MIN).checked_neg().unwrap_err(),
+            OverflowError::new(OverflowOperation::Neg)
+        );
+        assert_eq!(-Int256::from(Int128::new(7)), Int256::from(Int128::new(-7)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn int256_neg_panics_on_min() {
+        let _ = -Int256(I256::MIN);
+    }
+
+    #[test]
+    fn int256_uint256_conversions() {
+        let u = Uint256::from(Uint128::new(42));
+        let i = Int256::try_from(u).unwrap();
+        assert_eq!(i, Int256::from(Int128::new(42)));
+        assert_eq!(Uint256::try_from(i).unwrap(), u);
+        assert!(Uint256::try_from(Int256::from(Int128::new(-1))).is_err());
+    }
+
+    #[test]
+    fn int256_saturating_arithmetic_clamps_at_the_bounds() {
+        let max = Int256(I256::MAX);
+        let min = Int256(I256::MIN);
+        assert_eq!(max.saturating_add(Int256::from(Int128::new(1))), max);
+        assert_eq!(min.saturating_sub(Int256::from(Int128::new(1))), min);
+        assert_eq!(max.saturating_mul(Int256::from(Int128::new(2))), max);
+
+        // Within bounds, saturating and checked agree.
+        let a = Int256::from(Int128::new(10));
+        let b = Int256::from(Int128::new(3));
+        assert_eq!(a.saturating_add(b), a.checked_add(b).unwrap());
+    }
+}
@@ -19,6 +19,7 @@ mod deps;
 mod errors;
 mod ibc;
 mod import_helpers;
+mod int;
 #[cfg(feature = "iterator")]
 mod iterator;
 mod metadata;
@@ -46,21 +47,22 @@ pub mod storage_keys;
 pub use crate::addresses::{instantiate2_address, Addr, CanonicalAddr, Instantiate2AddressError};
 pub use crate::checksum::{Checksum, ChecksumError};
 pub use crate::coin::{coin, coins, has_coins, Coin};
-pub use crate::coins::Coins;
+pub use crate::coins::{Coins, CoinsError, ParseCoinsError};
 pub use crate::deps::{Deps, DepsMut, OwnedDeps};
 pub use crate::errors::{
     CheckedFromRatioError, CheckedMultiplyFractionError, CheckedMultiplyRatioError,
-    CoinFromStrError, CoinsError, ConversionOverflowError, DivideByZeroError, DivisionError,
-    OverflowError, OverflowOperation, RecoverPubkeyError, StdError, StdResult, SystemError,
-    VerificationError,
+    CoinFromStrError, ConversionOverflowError, DisplayCoinError, DivideByZeroError,
+    DivisionError, OverflowError, OverflowOperation, RecoverPubkeyError, StdError, StdResult,
+    SystemError, VerificationError,
 };
 pub use crate::ibc::IbcChannelOpenResponse;
 pub use crate::ibc::{
-    Ibc3ChannelOpenResponse, IbcAcknowledgement, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
-    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcEndpoint, IbcMsg, IbcOrder, IbcPacket,
-    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout,
-    IbcTimeoutBlock,
+    DenomTrace, Ibc3ChannelOpenResponse, IbcAcknowledgement, IbcBasicResponse, IbcChannel,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcEndpoint, IbcForwardMemo,
+    IbcMsg, IbcOrder, IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
+    IbcReceiveResponse, IbcTimeout, IbcTimeoutBlock, ParseDenomTraceError,
 };
+pub use crate::int::{Int128, Int256, ParseInt256Error};
 #[cfg(feature = "iterator")]
 pub use crate::iterator::{Order, Record};
 pub use crate::metadata::{DenomMetadata, DenomUnit};
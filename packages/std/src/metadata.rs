@@ -0,0 +1,61 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::DisplayCoinError;
+
+/// A denomination unit, e.g. `{ denom: "atom", exponent: 6, aliases: [] }`,
+/// meaning `1 atom = 10^6 uatom`. Mirrors the Cosmos SDK bank module's
+/// `DenomUnit`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct DenomUnit {
+    pub denom: String,
+    pub exponent: u32,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl DenomUnit {
+    pub fn new(denom: impl Into<String>, exponent: u32) -> Self {
+        DenomUnit {
+            denom: denom.into(),
+            exponent,
+            aliases: vec![],
+        }
+    }
+}
+
+/// Metadata describing the display units of a token, as returned by the bank
+/// module's `DenomMetadata` query. This connects the base-unit amount stored
+/// in a [`crate::Coin`] to a human-readable display denom.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct DenomMetadata {
+    pub description: String,
+    pub denom_units: Vec<DenomUnit>,
+    pub base: String,
+    pub display: String,
+    pub name: String,
+    pub symbol: String,
+    #[serde(default)]
+    pub uri: String,
+    #[serde(default)]
+    pub uri_hash: String,
+}
+
+impl DenomMetadata {
+    /// The exponent of `base` itself, i.e. the unit the on-chain amount is
+    /// denominated in. Errors if `base` has no matching `denom_units` entry,
+    /// since that means the metadata is malformed and any scale factor
+    /// derived from it would be wrong.
+    pub fn base_exponent(&self) -> Result<u32, DisplayCoinError> {
+        self.unit_exponent(&self.base)
+            .ok_or_else(|| DisplayCoinError::UnknownUnit(self.base.clone()))
+    }
+
+    /// The exponent for the given denom unit, if present.
+    pub fn unit_exponent(&self, denom: &str) -> Option<u32> {
+        self.denom_units
+            .iter()
+            .find(|u| u.denom == denom || u.aliases.iter().any(|a| a == denom))
+            .map(|u| u.exponent)
+    }
+}